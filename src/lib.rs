@@ -2,24 +2,226 @@
 //! in that all functions take a `t ∈ [0, 1]` and return a value in the interval `[0, 1]`.
 //!
 //! Derived from https://github.com/warrenm/AHEasing/blob/master/AHEasing/easing.c
-use std::f64::consts::PI;
+//!
+//! The `std` feature is enabled by default; disable it (`default-features = false`) to make the
+//! crate `no_std`. The pure-polynomial easings (quadratic/cubic/quartic/quintic/back/bounce) need
+//! no floating point math library either way and are always available. The transcendental ones
+//! (sin/circular/exponential/elastic) need either the `std` feature or the `libm` feature, which
+//! supplies `sin`, `cos`, `sqrt` and `powf` via the [`libm`](https://docs.rs/libm) crate for
+//! `no_std` targets; with neither enabled those functions are not compiled in.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+/// Small shim so the transcendental easings can route through either `std` or `libm`, depending
+/// on which feature is enabled.
+#[cfg(feature = "std")]
+mod math {
+    pub(crate) fn sin_f64(x: f64) -> f64 {
+        x.sin()
+    }
+
+    pub(crate) fn cos_f64(x: f64) -> f64 {
+        x.cos()
+    }
+
+    pub(crate) fn sqrt_f64(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub(crate) fn powf_f64(x: f64, y: f64) -> f64 {
+        x.powf(y)
+    }
+
+    pub(crate) fn sin_f32(x: f32) -> f32 {
+        x.sin()
+    }
+
+    pub(crate) fn cos_f32(x: f32) -> f32 {
+        x.cos()
+    }
+
+    pub(crate) fn sqrt_f32(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    pub(crate) fn powf_f32(x: f32, y: f32) -> f32 {
+        x.powf(y)
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+mod math {
+    pub(crate) fn sin_f64(x: f64) -> f64 {
+        libm::sin(x)
+    }
+
+    pub(crate) fn cos_f64(x: f64) -> f64 {
+        libm::cos(x)
+    }
+
+    pub(crate) fn sqrt_f64(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    pub(crate) fn powf_f64(x: f64, y: f64) -> f64 {
+        libm::pow(x, y)
+    }
+
+    pub(crate) fn sin_f32(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+
+    pub(crate) fn cos_f32(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+
+    pub(crate) fn sqrt_f32(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+
+    pub(crate) fn powf_f32(x: f32, y: f32) -> f32 {
+        libm::powf(x, y)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating point type the easing functions in this crate can be generic over.
+///
+/// This is implemented for [`f32`] and [`f64`] only, and is sealed so it can't be implemented for
+/// anything else: it exists purely so callers driving animation with `f32` state (GPU uniforms,
+/// game state) don't have to cast to `f64` and back at every call site.
+pub trait Float:
+    sealed::Sealed
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+    + core::ops::Neg<Output = Self>
+{
+    /// π, at this type's precision.
+    const PI: Self;
+    /// `0.0`, at this type's precision.
+    const ZERO: Self;
+    /// `0.5`, at this type's precision.
+    const HALF: Self;
+    /// `1.0`, at this type's precision.
+    const ONE: Self;
+    /// `2.0`, at this type's precision.
+    const TWO: Self;
+
+    /// Converts an `f64` constant to this type.
+    ///
+    /// Used for constants that don't have a dedicated associated const above, such as the bounce
+    /// polynomial coefficients. For `f64` the value is returned unchanged; for `f32` it is cast
+    /// with `as`, rounding to the nearest `f32`.
+    fn from_f64(x: f64) -> Self;
+
+    /// Requires the `std` or `libm` feature; see the crate docs.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sin(self) -> Self;
+    /// Requires the `std` or `libm` feature; see the crate docs.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn cos(self) -> Self;
+    /// Requires the `std` or `libm` feature; see the crate docs.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sqrt(self) -> Self;
+    /// Requires the `std` or `libm` feature; see the crate docs.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn powf(self, y: Self) -> Self;
+}
+
+impl Float for f64 {
+    const PI: Self = core::f64::consts::PI;
+    const ZERO: Self = 0.;
+    const HALF: Self = 0.5;
+    const ONE: Self = 1.;
+    const TWO: Self = 2.;
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sin(self) -> Self {
+        math::sin_f64(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn cos(self) -> Self {
+        math::cos_f64(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sqrt(self) -> Self {
+        math::sqrt_f64(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn powf(self, y: Self) -> Self {
+        math::powf_f64(self, y)
+    }
+}
+
+impl Float for f32 {
+    const PI: Self = core::f32::consts::PI;
+    const ZERO: Self = 0.;
+    const HALF: Self = 0.5;
+    const ONE: Self = 1.;
+    const TWO: Self = 2.;
+
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sin(self) -> Self {
+        math::sin_f32(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn cos(self) -> Self {
+        math::cos_f32(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sqrt(self) -> Self {
+        math::sqrt_f32(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn powf(self, y: Self) -> Self {
+        math::powf_f32(self, y)
+    }
+}
 
 /// Modeled after the line `y = x`
 #[inline]
-pub fn linear(t: f64) -> f64 {
+pub fn linear<F: Float>(t: F) -> F {
     t
 }
 
 /// Modeled after the parabola `y = x^2`
 #[inline]
-pub fn quadratic_in(t: f64) -> f64 {
+pub fn quadratic_in<F: Float>(t: F) -> F {
     t * t
 }
 
 /// Modeled after the parabola `y = -x^2 + 2x`
 #[inline]
-pub fn quadratic_out(t: f64) -> f64 {
-    -(t * (t - 2.))
+pub fn quadratic_out<F: Float>(t: F) -> F {
+    -(t * (t - F::TWO))
 }
 
 /// Modeled after the piecewise quadratic
@@ -28,25 +230,36 @@ pub fn quadratic_out(t: f64) -> f64 {
 /// y = -(1/2)((2x-1)*(2x-3) - 1) ; [0.5, 1]
 /// ```
 #[inline]
-pub fn quadratic_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        2. * t * t
+pub fn quadratic_in_out<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::TWO * t * t
     } else {
-        (-2. * t * t) + (4. * t) - 1.
+        (-F::TWO * t * t) + (F::from_f64(4.) * t) - F::ONE
+    }
+}
+
+/// Modeled after `quadratic_out` for the first half and `quadratic_in` for the second,
+/// decelerating then accelerating.
+#[inline]
+pub fn quadratic_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * quadratic_out(F::TWO * t)
+    } else {
+        F::HALF * quadratic_in(F::TWO * t - F::ONE) + F::HALF
     }
 }
 
 /// Modeled after the cubic y = x^3
 #[inline]
-pub fn cubic_in(t: f64) -> f64 {
+pub fn cubic_in<F: Float>(t: F) -> F {
     t * t * t
 }
 
 /// Modeled after the cubic y = (x - 1)^3 + 1
 #[inline]
-pub fn cubic_out(t: f64) -> f64 {
-    let f = t - 1.;
-    f * f * f + 1.
+pub fn cubic_out<F: Float>(t: F) -> F {
+    let f = t - F::ONE;
+    f * f * f + F::ONE
 }
 
 /// Modeled after the piecewise cubic
@@ -55,26 +268,37 @@ pub fn cubic_out(t: f64) -> f64 {
 /// y = (1/2)((2x-2)^3 + 2) ; [0.5, 1]
 /// ```
 #[inline]
-pub fn cubic_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        4. * t * t * t
+pub fn cubic_in_out<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::from_f64(4.) * t * t * t
     } else {
-        let f = (2. * t) - 2.;
-        0.5 * f * f * f + 1.
+        let f = (F::TWO * t) - F::TWO;
+        F::HALF * f * f * f + F::ONE
+    }
+}
+
+/// Modeled after `cubic_out` for the first half and `cubic_in` for the second, decelerating then
+/// accelerating.
+#[inline]
+pub fn cubic_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * cubic_out(F::TWO * t)
+    } else {
+        F::HALF * cubic_in(F::TWO * t - F::ONE) + F::HALF
     }
 }
 
 /// Modeled after the quartic y = x^4
 #[inline]
-pub fn quartic_in(t: f64) -> f64 {
+pub fn quartic_in<F: Float>(t: F) -> F {
     t * t * t * t
 }
 
 /// Modeled after the quartic y = 1 - (x - 1)^4
 #[inline]
-pub fn quartic_out(t: f64) -> f64 {
-    let f = t - 1.;
-    f * f * f + (1. - t) + 1.
+pub fn quartic_out<F: Float>(t: F) -> F {
+    let f = t - F::ONE;
+    F::ONE - f * f * f * f
 }
 
 /// Modeled after the piecewise quartic
@@ -83,26 +307,37 @@ pub fn quartic_out(t: f64) -> f64 {
 /// y = -(1/2)((2x-2)^4 - 2) ; [0.5, 1]
 /// ```
 #[inline]
-pub fn quartic_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        8. * t * t * t * t
+pub fn quartic_in_out<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::from_f64(8.) * t * t * t * t
+    } else {
+        let f = t - F::ONE;
+        F::from_f64(-8.) * f * f * f * f + F::ONE
+    }
+}
+
+/// Modeled after `quartic_out` for the first half and `quartic_in` for the second, decelerating
+/// then accelerating.
+#[inline]
+pub fn quartic_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * quartic_out(F::TWO * t)
     } else {
-        let f = t - 1.;
-        -8. * f * f * f * f + 1.
+        F::HALF * quartic_in(F::TWO * t - F::ONE) + F::HALF
     }
 }
 
 /// Modeled after the quintic y = x^5
 #[inline]
-pub fn quintic_in(t: f64) -> f64 {
+pub fn quintic_in<F: Float>(t: F) -> F {
     t * t * t * t * t
 }
 
 /// Modeled after the quintic y = (x - 1)^5 + 1
 #[inline]
-pub fn quintic_out(t: f64) -> f64 {
-    let f = t - 1.;
-    f * f * f * f * f + 1.
+pub fn quintic_out<F: Float>(t: F) -> F {
+    let f = t - F::ONE;
+    f * f * f * f * f + F::ONE
 }
 
 /// Modeled after the piecewise quintic
@@ -111,43 +346,71 @@ pub fn quintic_out(t: f64) -> f64 {
 /// y = (1/2)((2x-2)^5 + 2) ; [0.5, 1]
 /// ```
 #[inline]
-pub fn quintic_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        16. * t * t * t * t * t
+pub fn quintic_in_out<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::from_f64(16.) * t * t * t * t * t
     } else {
-        let f = (2. * t) - 2.;
-        0.5 * f * f * f * f * f + 1.
+        let f = (F::TWO * t) - F::TWO;
+        F::HALF * f * f * f * f * f + F::ONE
+    }
+}
+
+/// Modeled after `quintic_out` for the first half and `quintic_in` for the second, decelerating
+/// then accelerating.
+#[inline]
+pub fn quintic_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * quintic_out(F::TWO * t)
+    } else {
+        F::HALF * quintic_in(F::TWO * t - F::ONE) + F::HALF
     }
 }
 
 /// Modeled after quarter-cycle of sine wave
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn sin_in(t: f64) -> f64 {
-    ((t - 1.) * 2. * PI).sin() + 1.
+pub fn sin_in<F: Float>(t: F) -> F {
+    ((t - F::ONE) * F::HALF * F::PI).sin() + F::ONE
 }
 
 /// Modeled after quarter-cycle of sine wave (different phase)
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn sin_out(t: f64) -> f64 {
-    (t * 2. * PI).sin()
+pub fn sin_out<F: Float>(t: F) -> F {
+    (t * F::HALF * F::PI).sin()
 }
 
 /// Modeled after half sine wave
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn sin_in_out<F: Float>(t: F) -> F {
+    F::HALF * (F::ONE - (t * F::PI).cos())
+}
+
+/// Modeled after `sin_out` for the first half and `sin_in` for the second, decelerating then
+/// accelerating.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn sin_in_out(t: f64) -> f64 {
-    0.5 * (1. - (t * PI).cos())
+pub fn sin_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * sin_out(F::TWO * t)
+    } else {
+        F::HALF * sin_in(F::TWO * t - F::ONE) + F::HALF
+    }
 }
 
 /// Modeled after shifted quadrant IV of unit circle
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn circular_in(t: f64) -> f64 {
-    1. - (1. - t * t).sqrt()
+pub fn circular_in<F: Float>(t: F) -> F {
+    F::ONE - (F::ONE - t * t).sqrt()
 }
 
 /// Modeled after shifted quadrant II of unit circle
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn circular_out(t: f64) -> f64 {
-    (2. - t).sqrt() * t
+pub fn circular_out<F: Float>(t: F) -> F {
+    ((F::TWO - t) * t).sqrt()
 }
 
 /// Modeled after the piecewise circular function
@@ -155,36 +418,51 @@ pub fn circular_out(t: f64) -> f64 {
 /// y = (1/2)(1 - sqrt(1 - 4x^2))           ; [0, 0.5)
 /// y = (1/2)(sqrt(-(2x - 3)*(2x - 1)) + 1) ; [0.5, 1]
 /// ```
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn circular_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        0.5 * (1. - (1. - 4. * t * t).sqrt())
+pub fn circular_in_out<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * (F::ONE - (F::ONE - F::from_f64(4.) * t * t).sqrt())
     } else {
-        0.5 * ((-(2. * t - 3.) * (2. * t - 1.)).sqrt() + 1.)
+        F::HALF * ((-(F::TWO * t - F::from_f64(3.)) * (F::TWO * t - F::ONE)).sqrt() + F::ONE)
+    }
+}
+
+/// Modeled after `circular_out` for the first half and `circular_in` for the second,
+/// decelerating then accelerating.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn circular_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * circular_out(F::TWO * t)
+    } else {
+        F::HALF * circular_in(F::TWO * t - F::ONE) + F::HALF
     }
 }
 
 /// Modeled after the exponential function y = 2^(10(x - 1))
 ///
 /// There is a small discontinuity at 0.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn exponential_in(t: f64) -> f64 {
-    if t == 0. {
+pub fn exponential_in<F: Float>(t: F) -> F {
+    if t == F::ZERO {
         t
     } else {
-        2.0f64.powf(10. * (t - 1.))
+        F::TWO.powf(F::from_f64(10.) * (t - F::ONE))
     }
 }
 
 /// Modeled after the exponential function y = -2^(-10x) + 1
 ///
 /// There is a small discontinuity at 1.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn exponential_out(t: f64) -> f64 {
-    if t == 1. {
+pub fn exponential_out<F: Float>(t: F) -> F {
+    if t == F::ONE {
         t
     } else {
-        1. - 2.0f64.powf(-10. * t)
+        F::ONE - F::TWO.powf(F::from_f64(-10.) * t)
     }
 }
 
@@ -195,109 +473,644 @@ pub fn exponential_out(t: f64) -> f64 {
 /// ```
 ///
 /// There is a small discontinuity at 0 and 1.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn exponential_in_out(t: f64) -> f64 {
-    if t == 0. || t == 1. {
+pub fn exponential_in_out<F: Float>(t: F) -> F {
+    if t == F::ZERO || t == F::ONE {
         t
-    } else if t < 0.5 {
-        0.5 * 2.0f64.powf(20. * t - 10.)
+    } else if t < F::HALF {
+        F::HALF * F::TWO.powf(F::from_f64(20.) * t - F::from_f64(10.))
     } else {
-        0.5 * 2.0f64.powf(-20. * t + 10.) + 1.
+        F::ONE - F::HALF * F::TWO.powf(F::from_f64(-20.) * t + F::from_f64(10.))
     }
 }
 
-/// Modeled after the damped sine wave y = sin(13pi/2*x)*pow(2, 10 * (x - 1))
+/// Modeled after `exponential_out` for the first half and `exponential_in` for the second,
+/// decelerating then accelerating.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn elastic_in(t: f64) -> f64 {
-    (13. * 2. * PI * t).sin() * 2.0f64.powf(10. * (t - 1.))
+pub fn exponential_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * exponential_out(F::TWO * t)
+    } else {
+        F::HALF * exponential_in(F::TWO * t - F::ONE) + F::HALF
+    }
 }
 
-/// Modeled after the damped sine wave y = sin(-13pi/2*(x + 1))*pow(2, -10x) + 1
+/// Modeled after the damped sine wave `y = -A*2^(10t-10)*sin((10t-10.75)*2pi/period)`, with a
+/// configurable amplitude `A` and oscillation `period`.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn elastic_out(t: f64) -> f64 {
-    (-13. * 2. * PI * (t + 1.)).sin() * 2.0f64.powf(10. * (t - 1.))
+pub fn elastic_in_with<F: Float>(t: F, amplitude: F, period: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        t
+    } else {
+        let angular_freq = (F::TWO * F::PI) / period;
+        -amplitude
+            * F::TWO.powf(F::from_f64(10.) * t - F::from_f64(10.))
+            * ((F::from_f64(10.) * t - F::from_f64(10.75)) * angular_freq).sin()
+    }
 }
 
-/// Modeled after the piecewise exponentially-damped sine wave:
-/// ```text
-/// y = (1/2)*sin(13pi/2*(2*x))*pow(2, 10 * ((2*x) - 1))      ; [0,0.5)
-/// y = (1/2)*(sin(-13pi/2*((2x-1)+1))*pow(2,-10(2*x-1)) + 2) ; [0.5, 1]
-/// ```
+/// Modeled after the damped sine wave `y = A*2^(-10t)*sin((10t-0.75)*2pi/period) + 1`, with a
+/// configurable amplitude `A` and oscillation `period`.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn elastic_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        0.5 * (13. * PI * 2. * 2. * t) * 2.0f64.powf(10. * (2. * t - 1.))
+pub fn elastic_out_with<F: Float>(t: F, amplitude: F, period: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        t
     } else {
-        0.5 * ((-13. * PI * 2. * (2. * t - 1.) + 1.).sin() * 2.0f64.powf(-10. * (2. * t - 1.)) + 2.)
+        let angular_freq = (F::TWO * F::PI) / period;
+        amplitude * F::TWO.powf(F::from_f64(-10.) * t) * ((F::from_f64(10.) * t - F::from_f64(0.75)) * angular_freq).sin()
+            + F::ONE
     }
 }
 
-/// Modeled after the overshooting cubic y = x^3-x*sin(x*pi)
+/// Modeled after the piecewise exponentially-damped sine wave formed from [`elastic_in_with`] and
+/// [`elastic_out_with`], with a configurable amplitude `A` and oscillation `period`.
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn back_in(t: f64) -> f64 {
-    t * t * t - t * (t * PI).sin()
+pub fn elastic_in_out_with<F: Float>(t: F, amplitude: F, period: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        t
+    } else {
+        let angular_freq = (F::TWO * F::PI) / period;
+        if t < F::HALF {
+            -(amplitude
+                * F::TWO.powf(F::from_f64(20.) * t - F::from_f64(10.))
+                * ((F::from_f64(20.) * t - F::from_f64(11.125)) * angular_freq).sin())
+                * F::HALF
+        } else {
+            amplitude
+                * F::TWO.powf(F::from_f64(-20.) * t + F::from_f64(10.))
+                * ((F::from_f64(20.) * t - F::from_f64(11.125)) * angular_freq).sin()
+                * F::HALF
+                + F::ONE
+        }
+    }
 }
 
-/// Modeled after overshooting cubic y = 1-((1-x)^3-(1-x)*sin((1-x)*pi))
+/// Modeled after the damped sine wave y = -2^(10x-10)*sin((10x-10.75)*2pi/3)
+///
+/// A thin wrapper over [`elastic_in_with`] with the classic amplitude (`1`) and period (`3`).
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn back_out(t: f64) -> f64 {
-    let f = 1. - t;
-    1. - (f * f * f - f * (f * PI).sin())
+pub fn elastic_in<F: Float>(t: F) -> F {
+    elastic_in_with(t, F::ONE, F::from_f64(3.))
 }
 
-/// Modeled after the piecewise overshooting cubic function:
-/// ```text
-/// y = (1/2)*((2x)^3-(2x)*sin(2*x*pi))           ; [0, 0.5)
-/// y = (1/2)*(1-((1-x)^3-(1-x)*sin((1-x)*pi))+1) ; [0.5, 1]
-/// ```
+/// Modeled after the damped sine wave y = 2^(-10x)*sin((10x-0.75)*2pi/3) + 1
+///
+/// A thin wrapper over [`elastic_out_with`] with the classic amplitude (`1`) and period (`3`).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn elastic_out<F: Float>(t: F) -> F {
+    elastic_out_with(t, F::ONE, F::from_f64(3.))
+}
+
+/// Modeled after the piecewise exponentially-damped sine wave formed from `elastic_in` and
+/// `elastic_out`.
+///
+/// A thin wrapper over [`elastic_in_out_with`] with the classic amplitude (`1`) and period
+/// (`4.5`).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn elastic_in_out<F: Float>(t: F) -> F {
+    elastic_in_out_with(t, F::ONE, F::from_f64(4.5))
+}
+
+/// Modeled after `elastic_out` for the first half and `elastic_in` for the second, decelerating
+/// then accelerating.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn elastic_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * elastic_out(F::TWO * t)
+    } else {
+        F::HALF * elastic_in(F::TWO * t - F::ONE) + F::HALF
+    }
+}
+
+/// Modeled after the overshooting cubic `y = (overshoot+1)*t^3 - overshoot*t^2`, with a
+/// configurable `overshoot`.
+#[inline]
+pub fn back_in_with<F: Float>(t: F, overshoot: F) -> F {
+    (overshoot + F::ONE) * t * t * t - overshoot * t * t
+}
+
+/// Modeled after the overshooting cubic `y = 1 + (overshoot+1)*(t-1)^3 + overshoot*(t-1)^2`, with
+/// a configurable `overshoot`.
+#[inline]
+pub fn back_out_with<F: Float>(t: F, overshoot: F) -> F {
+    let f = t - F::ONE;
+    F::ONE + (overshoot + F::ONE) * f * f * f + overshoot * f * f
+}
+
+/// Modeled after the piecewise overshooting cubic formed from [`back_in_with`] and
+/// [`back_out_with`], with a configurable `overshoot` (scaled by `1.525` for the piecewise form,
+/// as is standard for this curve).
+#[inline]
+pub fn back_in_out_with<F: Float>(t: F, overshoot: F) -> F {
+    let c2 = overshoot * F::from_f64(1.525);
+    if t < F::HALF {
+        let f = F::TWO * t;
+        F::HALF * (f * f * ((c2 + F::ONE) * f - c2))
+    } else {
+        let f = F::TWO * t - F::TWO;
+        F::HALF * (f * f * ((c2 + F::ONE) * f + c2) + F::TWO)
+    }
+}
+
+/// Modeled after the overshooting cubic y = (c+1)x^3 - cx^2
+///
+/// A thin wrapper over [`back_in_with`] with the classic overshoot (`1.70158`).
 #[inline]
-pub fn back_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        let f = 2. * t;
-        0.5 * (f * f * f - f * (f * PI).sin())
+pub fn back_in<F: Float>(t: F) -> F {
+    back_in_with(t, F::from_f64(1.70158))
+}
+
+/// Modeled after overshooting cubic y = 1 + (c+1)(x-1)^3 + c(x-1)^2
+///
+/// A thin wrapper over [`back_out_with`] with the classic overshoot (`1.70158`).
+#[inline]
+pub fn back_out<F: Float>(t: F) -> F {
+    back_out_with(t, F::from_f64(1.70158))
+}
+
+/// Modeled after the piecewise overshooting cubic formed from `back_in` and `back_out`.
+///
+/// A thin wrapper over [`back_in_out_with`] with the classic overshoot (`1.70158`).
+#[inline]
+pub fn back_in_out<F: Float>(t: F) -> F {
+    back_in_out_with(t, F::from_f64(1.70158))
+}
+
+/// Modeled after `back_out` for the first half and `back_in` for the second, decelerating then
+/// accelerating.
+#[inline]
+pub fn back_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * back_out(F::TWO * t)
     } else {
-        let f = 1. - (2. * t - 1.);
-        // not sure why we add & subtract 0.5 here - probably a stability thing
-        0.5 * (1. - (f * f * f - f * (f * PI).sin())) + 0.5
+        F::HALF * back_in(F::TWO * t - F::ONE) + F::HALF
     }
 }
 
 /// Each bounce is modelled as a parabola
 #[inline]
-pub fn bounce_in(t: f64) -> f64 {
-    1. - bounce_out(1. - t)
+pub fn bounce_in<F: Float>(t: F) -> F {
+    F::ONE - bounce_out(F::ONE - t)
 }
 
 /// Each bounce is modelled as a parabola
 #[inline]
-pub fn bounce_out(t: f64) -> f64 {
-    if t < 4. / 11. {
-        const T2: f64 = 121. / 16.;
-        T2 * t * t
-    } else if t < 8. / 11. {
-        const T2: f64 = 363. / 40.;
-        const T1: f64 = -99. / 10.;
-        const T0: f64 = 17. / 5.;
-        T2 * t * t + T1 * t + T0
-    } else if t < 9. / 10. {
-        const T2: f64 = 4356. / 361.;
-        const T1: f64 = -35442. / 1805.;
-        const T0: f64 = 16061. / 1805.;
-        T2 * t * t + T1 * t + T0
+pub fn bounce_out<F: Float>(t: F) -> F {
+    if t < F::from_f64(4. / 11.) {
+        let t2 = F::from_f64(121. / 16.);
+        t2 * t * t
+    } else if t < F::from_f64(8. / 11.) {
+        let t2 = F::from_f64(363. / 40.);
+        let t1 = F::from_f64(-99. / 10.);
+        let t0 = F::from_f64(17. / 5.);
+        t2 * t * t + t1 * t + t0
+    } else if t < F::from_f64(9. / 10.) {
+        let t2 = F::from_f64(4356. / 361.);
+        let t1 = F::from_f64(-35442. / 1805.);
+        let t0 = F::from_f64(16061. / 1805.);
+        t2 * t * t + t1 * t + t0
     } else {
-        const T2: f64 = 54. / 5.;
-        const T1: f64 = -513. / 25.;
-        const T0: f64 = 268. / 25.;
-        T2 * t * t + T1 * t + T0
+        let t2 = F::from_f64(54. / 5.);
+        let t1 = F::from_f64(-513. / 25.);
+        let t0 = F::from_f64(268. / 25.);
+        t2 * t * t + t1 * t + t0
     }
 }
 
 /// Each bounce is modelled as a parabola
 #[inline]
-pub fn bounce_in_out(t: f64) -> f64 {
-    if t < 0.5 {
-        0.5 * bounce_in(t * 2.)
+pub fn bounce_in_out<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * bounce_in(t * F::TWO)
     } else {
-        0.5 * bounce_out(t * 2. - 1.) + 0.5
+        F::HALF * bounce_out(t * F::TWO - F::ONE) + F::HALF
+    }
+}
+
+/// Modeled after `bounce_out` for the first half and `bounce_in` for the second, decelerating
+/// then accelerating.
+#[inline]
+pub fn bounce_out_in<F: Float>(t: F) -> F {
+    if t < F::HALF {
+        F::HALF * bounce_out(F::TWO * t)
+    } else {
+        F::HALF * bounce_in(F::TWO * t - F::ONE) + F::HALF
+    }
+}
+
+/// A runtime-selectable choice of easing function.
+///
+/// This is useful anywhere the curve itself is a parameter rather than something baked into the
+/// call site, e.g. stored in a config, exposed in a UI, or sent over the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    QuadraticOutIn,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    CubicOutIn,
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+    QuarticOutIn,
+    QuinticIn,
+    QuinticOut,
+    QuinticInOut,
+    QuinticOutIn,
+    SinIn,
+    SinOut,
+    SinInOut,
+    SinOutIn,
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+    CircularOutIn,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    ExponentialOutIn,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    ElasticOutIn,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BackOutIn,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    BounceOutIn,
+}
+
+impl Easing {
+    /// Every variant, in declaration order.
+    pub const ALL: &'static [Easing] = &[
+        Easing::Linear,
+        Easing::QuadraticIn,
+        Easing::QuadraticOut,
+        Easing::QuadraticInOut,
+        Easing::QuadraticOutIn,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::CubicOutIn,
+        Easing::QuarticIn,
+        Easing::QuarticOut,
+        Easing::QuarticInOut,
+        Easing::QuarticOutIn,
+        Easing::QuinticIn,
+        Easing::QuinticOut,
+        Easing::QuinticInOut,
+        Easing::QuinticOutIn,
+        Easing::SinIn,
+        Easing::SinOut,
+        Easing::SinInOut,
+        Easing::SinOutIn,
+        Easing::CircularIn,
+        Easing::CircularOut,
+        Easing::CircularInOut,
+        Easing::CircularOutIn,
+        Easing::ExponentialIn,
+        Easing::ExponentialOut,
+        Easing::ExponentialInOut,
+        Easing::ExponentialOutIn,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::ElasticOutIn,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::BackOutIn,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+        Easing::BounceOutIn,
+    ];
+
+    /// Apply the selected easing function to `t`.
+    ///
+    /// Requires the `std` or `libm` feature, since some variants are transcendental.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn apply(self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => linear(t),
+            Easing::QuadraticIn => quadratic_in(t),
+            Easing::QuadraticOut => quadratic_out(t),
+            Easing::QuadraticInOut => quadratic_in_out(t),
+            Easing::QuadraticOutIn => quadratic_out_in(t),
+            Easing::CubicIn => cubic_in(t),
+            Easing::CubicOut => cubic_out(t),
+            Easing::CubicInOut => cubic_in_out(t),
+            Easing::CubicOutIn => cubic_out_in(t),
+            Easing::QuarticIn => quartic_in(t),
+            Easing::QuarticOut => quartic_out(t),
+            Easing::QuarticInOut => quartic_in_out(t),
+            Easing::QuarticOutIn => quartic_out_in(t),
+            Easing::QuinticIn => quintic_in(t),
+            Easing::QuinticOut => quintic_out(t),
+            Easing::QuinticInOut => quintic_in_out(t),
+            Easing::QuinticOutIn => quintic_out_in(t),
+            Easing::SinIn => sin_in(t),
+            Easing::SinOut => sin_out(t),
+            Easing::SinInOut => sin_in_out(t),
+            Easing::SinOutIn => sin_out_in(t),
+            Easing::CircularIn => circular_in(t),
+            Easing::CircularOut => circular_out(t),
+            Easing::CircularInOut => circular_in_out(t),
+            Easing::CircularOutIn => circular_out_in(t),
+            Easing::ExponentialIn => exponential_in(t),
+            Easing::ExponentialOut => exponential_out(t),
+            Easing::ExponentialInOut => exponential_in_out(t),
+            Easing::ExponentialOutIn => exponential_out_in(t),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => elastic_out(t),
+            Easing::ElasticInOut => elastic_in_out(t),
+            Easing::ElasticOutIn => elastic_out_in(t),
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => back_out(t),
+            Easing::BackInOut => back_in_out(t),
+            Easing::BackOutIn => back_out_in(t),
+            Easing::BounceIn => bounce_in(t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => bounce_in_out(t),
+            Easing::BounceOutIn => bounce_out_in(t),
+        }
+    }
+}
+
+/// Error returned when parsing an [`Easing`] from a string that doesn't name a known variant.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEasingError(String);
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown easing name: {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEasingError {}
+
+#[cfg(feature = "std")]
+impl FromStr for Easing {
+    type Err = ParseEasingError;
+
+    /// Parses names in `snake_case`, e.g. `"quadratic_in_out"`, `"bounce_in"`, `"linear"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "linear" => Easing::Linear,
+            "quadratic_in" => Easing::QuadraticIn,
+            "quadratic_out" => Easing::QuadraticOut,
+            "quadratic_in_out" => Easing::QuadraticInOut,
+            "quadratic_out_in" => Easing::QuadraticOutIn,
+            "cubic_in" => Easing::CubicIn,
+            "cubic_out" => Easing::CubicOut,
+            "cubic_in_out" => Easing::CubicInOut,
+            "cubic_out_in" => Easing::CubicOutIn,
+            "quartic_in" => Easing::QuarticIn,
+            "quartic_out" => Easing::QuarticOut,
+            "quartic_in_out" => Easing::QuarticInOut,
+            "quartic_out_in" => Easing::QuarticOutIn,
+            "quintic_in" => Easing::QuinticIn,
+            "quintic_out" => Easing::QuinticOut,
+            "quintic_in_out" => Easing::QuinticInOut,
+            "quintic_out_in" => Easing::QuinticOutIn,
+            "sin_in" => Easing::SinIn,
+            "sin_out" => Easing::SinOut,
+            "sin_in_out" => Easing::SinInOut,
+            "sin_out_in" => Easing::SinOutIn,
+            "circular_in" => Easing::CircularIn,
+            "circular_out" => Easing::CircularOut,
+            "circular_in_out" => Easing::CircularInOut,
+            "circular_out_in" => Easing::CircularOutIn,
+            "exponential_in" => Easing::ExponentialIn,
+            "exponential_out" => Easing::ExponentialOut,
+            "exponential_in_out" => Easing::ExponentialInOut,
+            "exponential_out_in" => Easing::ExponentialOutIn,
+            "elastic_in" => Easing::ElasticIn,
+            "elastic_out" => Easing::ElasticOut,
+            "elastic_in_out" => Easing::ElasticInOut,
+            "elastic_out_in" => Easing::ElasticOutIn,
+            "back_in" => Easing::BackIn,
+            "back_out" => Easing::BackOut,
+            "back_in_out" => Easing::BackInOut,
+            "back_out_in" => Easing::BackOutIn,
+            "bounce_in" => Easing::BounceIn,
+            "bounce_out" => Easing::BounceOut,
+            "bounce_in_out" => Easing::BounceInOut,
+            "bounce_out_in" => Easing::BounceOutIn,
+            other => return Err(ParseEasingError(other.to_string())),
+        })
+    }
+}
+
+/// Types that can be linearly interpolated between two values.
+///
+/// This is what lets [`EasingCurve`] tween arbitrary values rather than just `f64 ∈ [0, 1]`.
+pub trait Lerp {
+    /// Interpolates between `self` and `other`, where `t = 0` yields `self` and `t = 1` yields
+    /// `other`.
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t as f32
+    }
+}
+
+impl<A: Lerp, B: Lerp> Lerp for (A, B) {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        (self.0.lerp(&other.0, t), self.1.lerp(&other.1, t))
+    }
+}
+
+impl<A: Lerp, B: Lerp, C: Lerp> Lerp for (A, B, C) {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        (
+            self.0.lerp(&other.0, t),
+            self.1.lerp(&other.1, t),
+            self.2.lerp(&other.2, t),
+        )
+    }
+}
+
+impl<T: Lerp, const N: usize> Lerp for [T; N] {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        core::array::from_fn(|i| self[i].lerp(&other[i], t))
+    }
+}
+
+/// A curve that tweens between `start` and `end` using an [`Easing`].
+///
+/// Where the free functions in this crate map `t ∈ [0, 1] → [0, 1]`, `EasingCurve` remaps that
+/// eased parameter into an arbitrary value range, so callers don't have to re-derive the lerp at
+/// every call site.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EasingCurve<T> {
+    pub start: T,
+    pub end: T,
+    pub easing: Easing,
+}
+
+impl<T: Lerp> EasingCurve<T> {
+    /// Creates a new curve tweening from `start` to `end` using `easing`.
+    pub fn new(start: T, end: T, easing: Easing) -> Self {
+        EasingCurve { start, end, easing }
+    }
+
+    /// Applies the easing function to `t`, without remapping into `[start, end]`.
+    ///
+    /// `t` is clamped to `[0, 1]` first.
+    ///
+    /// Requires the `std` or `libm` feature, since some variants are transcendental.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn ease(&self, t: f64) -> f64 {
+        self.easing.apply(t.clamp(0., 1.))
+    }
+
+    /// Samples the curve at `t ∈ [0, 1]`, returning a value interpolated between `start` and
+    /// `end`.
+    ///
+    /// `t` is clamped to `[0, 1]` first.
+    ///
+    /// Requires the `std` or `libm` feature, since some variants are transcendental.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn sample(&self, t: f64) -> T {
+        self.start.lerp(&self.end, self.ease(t))
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn approx_eq(a: f64, b: f64) {
+        assert!(
+            (a - b).abs() < EPSILON,
+            "expected {b} but got {a} (diff {})",
+            (a - b).abs()
+        );
+    }
+
+    /// Every `_out_in` variant should start at 0, end at 1, and land on 0.5 at the midpoint, since
+    /// it's built by halving and stitching together the corresponding `_out` and `_in` functions.
+    macro_rules! test_out_in_endpoints {
+        ($name:ident, $f:path) => {
+            #[test]
+            fn $name() {
+                approx_eq($f(0.0), 0.0);
+                approx_eq($f(1.0), 1.0);
+                approx_eq($f(0.5), 0.5);
+            }
+        };
+    }
+
+    test_out_in_endpoints!(quadratic_out_in_endpoints, quadratic_out_in);
+    test_out_in_endpoints!(cubic_out_in_endpoints, cubic_out_in);
+    test_out_in_endpoints!(quartic_out_in_endpoints, quartic_out_in);
+    test_out_in_endpoints!(quintic_out_in_endpoints, quintic_out_in);
+    test_out_in_endpoints!(sin_out_in_endpoints, sin_out_in);
+    test_out_in_endpoints!(circular_out_in_endpoints, circular_out_in);
+    test_out_in_endpoints!(exponential_out_in_endpoints, exponential_out_in);
+    test_out_in_endpoints!(elastic_out_in_endpoints, elastic_out_in);
+    test_out_in_endpoints!(back_out_in_endpoints, back_out_in);
+    test_out_in_endpoints!(bounce_out_in_endpoints, bounce_out_in);
+
+    /// `_out_in` functions are stitched together from two halves; a buggy half can leave a jump at
+    /// the seam even when the endpoints happen to look right, so check continuity there too.
+    macro_rules! test_out_in_continuous {
+        ($name:ident, $f:path) => {
+            #[test]
+            fn $name() {
+                let before: f64 = $f(0.5 - 1e-6);
+                let after: f64 = $f(0.5 + 1e-6);
+                assert!(
+                    (before - after).abs() < 1e-3,
+                    "discontinuity at t=0.5: f(0.5-e)={before}, f(0.5+e)={after}"
+                );
+            }
+        };
+    }
+
+    test_out_in_continuous!(quadratic_out_in_continuous, quadratic_out_in);
+    test_out_in_continuous!(cubic_out_in_continuous, cubic_out_in);
+    test_out_in_continuous!(quartic_out_in_continuous, quartic_out_in);
+    test_out_in_continuous!(quintic_out_in_continuous, quintic_out_in);
+    test_out_in_continuous!(sin_out_in_continuous, sin_out_in);
+    test_out_in_continuous!(circular_out_in_continuous, circular_out_in);
+    test_out_in_continuous!(exponential_out_in_continuous, exponential_out_in);
+    test_out_in_continuous!(elastic_out_in_continuous, elastic_out_in);
+    test_out_in_continuous!(back_out_in_continuous, back_out_in);
+    test_out_in_continuous!(bounce_out_in_continuous, bounce_out_in);
+
+    #[test]
+    fn sin_in_and_sin_out_endpoints() {
+        approx_eq(sin_in(0.0), 0.0);
+        approx_eq(sin_in(1.0), 1.0);
+        approx_eq(sin_out(0.0), 0.0);
+        approx_eq(sin_out(1.0), 1.0);
+    }
+
+    #[test]
+    fn back_with_endpoints() {
+        approx_eq(back_in_with(0.0, 1.70158), 0.0);
+        approx_eq(back_in_with(1.0, 1.70158), 1.0);
+        approx_eq(back_out_with(0.0, 1.70158), 0.0);
+        approx_eq(back_out_with(1.0, 1.70158), 1.0);
+        approx_eq(back_in_out_with(0.0, 1.70158), 0.0);
+        approx_eq(back_in_out_with(1.0, 1.70158), 1.0);
+    }
+
+    #[test]
+    fn elastic_with_endpoints() {
+        approx_eq(elastic_in_with(0.0, 1.0, 3.0), 0.0);
+        approx_eq(elastic_in_with(1.0, 1.0, 3.0), 1.0);
+        approx_eq(elastic_out_with(0.0, 1.0, 3.0), 0.0);
+        approx_eq(elastic_out_with(1.0, 1.0, 3.0), 1.0);
+        approx_eq(elastic_in_out_with(0.0, 1.0, 4.5), 0.0);
+        approx_eq(elastic_in_out_with(1.0, 1.0, 4.5), 1.0);
+    }
+
+    #[test]
+    fn exponential_in_out_endpoints_and_continuity() {
+        approx_eq(exponential_in_out(0.0), 0.0);
+        approx_eq(exponential_in_out(1.0), 1.0);
+        let before: f64 = exponential_in_out(0.5 - 1e-6);
+        let after: f64 = exponential_in_out(0.5 + 1e-6);
+        assert!(
+            (before - after).abs() < 1e-3,
+            "discontinuity at t=0.5: f(0.5-e)={before}, f(0.5+e)={after}"
+        );
+    }
+
+    #[test]
+    fn circular_out_value() {
+        approx_eq(circular_out(0.5), (0.75_f64).sqrt());
     }
 }